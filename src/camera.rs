@@ -0,0 +1,119 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::capability::{check_format_supported, config_rejected_during_probe};
+use crate::{CaptureAPIBackend, FrameFormat, NokhwaError, Resolution};
+
+/// A handle to a single capture device.
+///
+/// This only models the surface `Camera::is_format_supported` needs: the
+/// backend it was opened against, and the `(format, resolution, fps)` triples
+/// the backend enumerated for it at construction time.
+pub struct Camera {
+    backend: CaptureAPIBackend,
+    compatible_formats: Vec<(FrameFormat, Resolution, u32)>,
+}
+
+impl Camera {
+    /// Checks whether `(format, resolution, fps)` is usable on this device
+    /// without opening a stream.
+    ///
+    /// This first checks the triple against the formats enumerated for the
+    /// device, then — if that looks compatible — asks the backend to
+    /// validate the configuration the same way it would when actually
+    /// opening a stream, so a rejection the enumeration data doesn't capture
+    /// (e.g. a MediaFoundation `GUID` the device advertises but won't
+    /// actually accept) still comes back as
+    /// [`NokhwaError::FormatNotSupported`] instead of surfacing only once
+    /// `open_stream` fails.
+    pub fn is_format_supported(
+        &self,
+        format: FrameFormat,
+        resolution: Resolution,
+        fps: u32,
+    ) -> Result<(), NokhwaError> {
+        check_format_supported(&self.compatible_formats, format, resolution, fps)?;
+        self.probe_backend_config(format, resolution, fps)
+    }
+
+    fn probe_backend_config(
+        &self,
+        format: FrameFormat,
+        resolution: Resolution,
+        fps: u32,
+    ) -> Result<(), NokhwaError> {
+        match self.backend {
+            #[cfg(all(feature = "input-msmf", target_os = "windows"))]
+            CaptureAPIBackend::MediaFoundation => {
+                msmf::probe_config(format, resolution, fps).map_err(|error| match error {
+                    nokhwa_bindings_windows::BindingError::GUIDSetError(_, _, reason) => {
+                        config_rejected_during_probe(
+                            format,
+                            &self.available_formats(),
+                            reason,
+                        )
+                    }
+                    other => NokhwaError::from(other),
+                })
+            }
+            #[cfg(all(
+                feature = "input-avfoundation",
+                any(target_os = "macos", target_os = "ios")
+            ))]
+            CaptureAPIBackend::AVFoundation => {
+                avfoundation::probe_config(format, resolution, fps).map_err(|error| match error {
+                    nokhwa_bindings_macos::AVFError::ConfigNotAccepted => {
+                        config_rejected_during_probe(
+                            format,
+                            &self.available_formats(),
+                            "Rejected by AVFoundation during format probe".to_string(),
+                        )
+                    }
+                    other => NokhwaError::from(other),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn available_formats(&self) -> Vec<FrameFormat> {
+        self.compatible_formats
+            .iter()
+            .map(|(format, _, _)| *format)
+            .collect()
+    }
+}
+
+#[cfg(all(feature = "input-msmf", target_os = "windows"))]
+mod msmf {
+    use crate::{FrameFormat, Resolution};
+    use nokhwa_bindings_windows::BindingError;
+
+    pub(super) fn probe_config(
+        format: FrameFormat,
+        resolution: Resolution,
+        fps: u32,
+    ) -> Result<(), BindingError> {
+        nokhwa_bindings_windows::probe_media_type(format, resolution, fps)
+    }
+}
+
+#[cfg(all(
+    feature = "input-avfoundation",
+    any(target_os = "macos", target_os = "ios")
+))]
+mod avfoundation {
+    use crate::{FrameFormat, Resolution};
+    use nokhwa_bindings_macos::AVFError;
+
+    pub(super) fn probe_config(
+        format: FrameFormat,
+        resolution: Resolution,
+        fps: u32,
+    ) -> Result<(), AVFError> {
+        nokhwa_bindings_macos::probe_format(format, resolution, fps)
+    }
+}
@@ -0,0 +1,134 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::{FrameFormat, NokhwaError, Resolution};
+
+/// Backs `Camera::is_format_supported`: checks a `(format, resolution, fps)`
+/// triple against the formats the backend actually enumerated for a device,
+/// without opening a stream.
+///
+/// This exists so callers can negotiate a working configuration up front
+/// instead of discovering an unsupported combination only once
+/// `Camera::open_stream` fails.
+pub(crate) fn check_format_supported(
+    available: &[(FrameFormat, Resolution, u32)],
+    requested_format: FrameFormat,
+    requested_resolution: Resolution,
+    requested_fps: u32,
+) -> Result<(), NokhwaError> {
+    let matches_format = available
+        .iter()
+        .any(|(format, _, _)| *format == requested_format);
+    if !matches_format {
+        return Err(NokhwaError::FormatNotSupported {
+            requested: requested_format,
+            available: available.iter().map(|(format, _, _)| *format).collect(),
+            reason: "no camera format with this pixel encoding was enumerated".to_string(),
+        });
+    }
+
+    let matches_exact = available.iter().any(|(format, resolution, fps)| {
+        *format == requested_format
+            && *resolution == requested_resolution
+            && *fps == requested_fps
+    });
+    if !matches_exact {
+        return Err(NokhwaError::FormatNotSupported {
+            requested: requested_format,
+            available: available.iter().map(|(format, _, _)| *format).collect(),
+            reason: format!(
+                "{requested_format} is supported, but not at {requested_resolution}@{requested_fps}fps"
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Maps a `ConfigNotAccepted`/`GUIDSetError`-style rejection raised while
+/// probing a format (rather than while actually applying one) onto
+/// [`NokhwaError::FormatNotSupported`], so `Camera::is_format_supported`
+/// gives callers the enumerated alternatives instead of a bare set-property
+/// failure.
+pub(crate) fn config_rejected_during_probe(
+    requested: FrameFormat,
+    available: &[FrameFormat],
+    reason: String,
+) -> NokhwaError {
+    NokhwaError::FormatNotSupported {
+        requested,
+        available: available.to_vec(),
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn formats() -> Vec<(FrameFormat, Resolution, u32)> {
+        vec![
+            (FrameFormat::MJPEG, Resolution::new(1280, 720), 30),
+            (FrameFormat::YUYV, Resolution::new(640, 480), 60),
+        ]
+    }
+
+    #[test]
+    fn exact_match_is_supported() {
+        assert!(check_format_supported(
+            &formats(),
+            FrameFormat::MJPEG,
+            Resolution::new(1280, 720),
+            30
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn unenumerated_format_is_rejected() {
+        let err = check_format_supported(
+            &formats(),
+            FrameFormat::H264,
+            Resolution::new(1280, 720),
+            30,
+        )
+        .unwrap_err();
+        assert!(matches!(err, NokhwaError::FormatNotSupported { requested: FrameFormat::H264, .. }));
+    }
+
+    #[test]
+    fn enumerated_format_at_wrong_resolution_is_rejected() {
+        let err = check_format_supported(
+            &formats(),
+            FrameFormat::MJPEG,
+            Resolution::new(640, 480),
+            30,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            NokhwaError::FormatNotSupported { requested: FrameFormat::MJPEG, .. }
+        ));
+    }
+
+    #[test]
+    fn config_rejected_during_probe_carries_available_formats() {
+        let available = vec![FrameFormat::MJPEG, FrameFormat::YUYV];
+        let err = config_rejected_during_probe(FrameFormat::H264, &available, "rejected".to_string());
+        match err {
+            NokhwaError::FormatNotSupported {
+                requested,
+                available: got,
+                reason,
+            } => {
+                assert_eq!(requested, FrameFormat::H264);
+                assert_eq!(got, available);
+                assert_eq!(reason, "rejected");
+            }
+            _ => panic!("expected FormatNotSupported"),
+        }
+    }
+}
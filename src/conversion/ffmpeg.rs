@@ -0,0 +1,150 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! FFmpeg-backed decode/convert stage, enabled by the `ffmpeg` feature.
+//!
+//! Some backends hand back frames already compressed (H.264, MJPEG) rather
+//! than as raw RGB/YUV. The built-in converters can't do anything with those;
+//! this module wires `ffmpeg-next` in as an additional stage so they convert
+//! to RGB/YUV the same way a software-only source would.
+
+use crate::{ConversionBackend, FrameFormat, NokhwaError};
+use ffmpeg_next::{self as ffmpeg, codec, format::Pixel};
+
+/// Decodes a compressed frame (e.g. H.264 or MJPEG) into the destination
+/// pixel format using FFmpeg, returning a [`NokhwaError::ProcessFrameError`]
+/// tagged with [`ConversionBackend::Ffmpeg`] on failure.
+pub fn decode_frame(
+    src_format: FrameFormat,
+    data: &[u8],
+    destination: Pixel,
+) -> Result<Vec<u8>, NokhwaError> {
+    let codec_id = codec_id_for(src_format, destination)?;
+
+    let decoder_codec = ffmpeg::decoder::find(codec_id).ok_or_else(|| {
+        process_frame_error(
+            src_format,
+            destination,
+            format!("no ffmpeg decoder registered for {codec_id:?}"),
+        )
+    })?;
+
+    let mut decoder = ffmpeg::codec::Context::new_with_codec(decoder_codec)
+        .decoder()
+        .video()
+        .map_err(|error| process_frame_error(src_format, destination, averror_to_string(error)))?;
+
+    let packet = ffmpeg::Packet::copy(data);
+    decoder
+        .send_packet(&packet)
+        .map_err(|error| process_frame_error(src_format, destination, averror_to_string(error)))?;
+
+    let mut decoded = ffmpeg::util::frame::Video::empty();
+    decoder
+        .receive_frame(&mut decoded)
+        .map_err(|error| process_frame_error(src_format, destination, averror_to_string(error)))?;
+
+    let mut scaler = ffmpeg::software::scaling::Context::get(
+        decoded.format(),
+        decoded.width(),
+        decoded.height(),
+        destination,
+        decoded.width(),
+        decoded.height(),
+        ffmpeg::software::scaling::Flags::BILINEAR,
+    )
+    .map_err(|error| process_frame_error(src_format, destination, averror_to_string(error)))?;
+
+    let mut converted = ffmpeg::util::frame::Video::empty();
+    scaler
+        .run(&decoded, &mut converted)
+        .map_err(|error| process_frame_error(src_format, destination, averror_to_string(error)))?;
+
+    let mut out = Vec::new();
+    for plane in 0..converted.planes() {
+        out.extend_from_slice(converted.data(plane));
+    }
+    Ok(out)
+}
+
+fn codec_id_for(
+    src_format: FrameFormat,
+    destination: Pixel,
+) -> Result<codec::Id, NokhwaError> {
+    match src_format {
+        FrameFormat::H264 => Ok(codec::Id::H264),
+        FrameFormat::MJPEG => Ok(codec::Id::MJPEG),
+        _ => Err(process_frame_error(
+            src_format,
+            destination,
+            "source format has no ffmpeg-decodable codec mapping".to_string(),
+        )),
+    }
+}
+
+fn process_frame_error(
+    src_format: FrameFormat,
+    destination: Pixel,
+    error: String,
+) -> NokhwaError {
+    NokhwaError::ProcessFrameError {
+        src: src_format,
+        destination: format!("{destination:?}"),
+        backend: ConversionBackend::Ffmpeg,
+        error,
+    }
+}
+
+fn averror_to_string(error: ffmpeg::Error) -> String {
+    // `ffmpeg::Error` carries the raw libav `AVERROR` only in `Other { errno }`;
+    // every other variant already has a descriptive `Display` impl, so fall
+    // back to that rather than assuming a numeric errno exists.
+    match &error {
+        ffmpeg::Error::Other { errno } => format!("{error} ({errno})"),
+        _ => error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn h264_and_mjpeg_map_to_their_codec_ids() {
+        assert_eq!(
+            codec_id_for(FrameFormat::H264, Pixel::RGB24).unwrap(),
+            codec::Id::H264
+        );
+        assert_eq!(
+            codec_id_for(FrameFormat::MJPEG, Pixel::RGB24).unwrap(),
+            codec::Id::MJPEG
+        );
+    }
+
+    #[test]
+    fn format_without_a_decodable_codec_is_rejected() {
+        let err = codec_id_for(FrameFormat::NV12, Pixel::RGB24).unwrap_err();
+        assert!(matches!(
+            err,
+            NokhwaError::ProcessFrameError {
+                backend: ConversionBackend::Ffmpeg,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn averror_other_includes_errno() {
+        let message = averror_to_string(ffmpeg::Error::Other { errno: 42 });
+        assert!(message.contains('4') && message.contains('2'));
+    }
+
+    #[test]
+    fn averror_non_other_uses_display() {
+        let message = averror_to_string(ffmpeg::Error::Eof);
+        assert_eq!(message, ffmpeg::Error::Eof.to_string());
+    }
+}
@@ -0,0 +1,13 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Frame decode/convert stages. The built-in converters handle the pixel
+//! formats OS backends deliver uncompressed; the optional `ffmpeg` feature
+//! adds a stage for compressed source codecs (H.264, MJPEG, ...) that a
+//! backend may hand back instead.
+
+#[cfg(feature = "ffmpeg")]
+pub mod ffmpeg;
@@ -7,6 +7,54 @@
 use crate::{CaptureAPIBackend, FrameFormat};
 use thiserror::Error;
 
+/// The reason a stream-level operation (opening a stream or reading a frame
+/// from one) failed.
+///
+/// This is what lets a capture loop tell a transient, backend-specific hiccup
+/// apart from the device having gone away entirely: the former is usually
+/// worth retrying, the latter means the device needs to be re-enumerated.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum StreamErrorKind {
+    #[error("device is not available")]
+    DeviceNotAvailable,
+    #[error("{0}")]
+    BackendSpecific(String),
+}
+
+/// Turns the structured `device_lost` signal a binding layer reports
+/// alongside a stream failure into a [`StreamErrorKind`].
+///
+/// This is deliberately *not* based on sniffing the free-form error message:
+/// that string is a `Display` impl meant for humans, not a contract, so the
+/// binding crate classifies the failure itself (e.g. from the HRESULT or
+/// `AVCaptureSessionInterruptionReason` it observed) and hands us a bool.
+fn stream_error_kind(device_lost: bool, error: String) -> StreamErrorKind {
+    if device_lost {
+        StreamErrorKind::DeviceNotAvailable
+    } else {
+        StreamErrorKind::BackendSpecific(error)
+    }
+}
+
+/// Which frame conversion implementation produced a [`NokhwaError::ProcessFrameError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionBackend {
+    /// One of `nokhwa`'s built-in software converters.
+    Builtin,
+    /// The optional `ffmpeg`-backed decode/convert path.
+    Ffmpeg,
+}
+
+impl std::fmt::Display for ConversionBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversionBackend::Builtin => write!(f, "builtin"),
+            ConversionBackend::Ffmpeg => write!(f, "ffmpeg"),
+        }
+    }
+}
+
 /// All errors in `nokhwa`.
 #[allow(clippy::module_name_repetitions)]
 #[derive(Error, Debug, Clone)]
@@ -35,14 +83,21 @@ pub enum NokhwaError {
         value: String,
         error: String,
     },
-    #[error("Could not open device stream: {0}")]
-    OpenStreamError(String),
-    #[error("Could not capture frame: {0}")]
-    ReadFrameError(String),
-    #[error("Could not process frame {src} to {destination}: {error}")]
+    #[error("Could not open device stream: {error}")]
+    OpenStreamError {
+        kind: StreamErrorKind,
+        error: String,
+    },
+    #[error("Could not capture frame: {error}")]
+    ReadFrameError {
+        kind: StreamErrorKind,
+        error: String,
+    },
+    #[error("Could not process frame {src} to {destination} via {backend}: {error}")]
     ProcessFrameError {
         src: FrameFormat,
         destination: String,
+        backend: ConversionBackend,
         error: String,
     },
     #[error("Could not stop stream: {0}")]
@@ -51,6 +106,121 @@ pub enum NokhwaError {
     UnsupportedOperationError(CaptureAPIBackend),
     #[error("This operation is not implemented yet: {0}")]
     NotImplementedError(String),
+    #[error("Device {0} has been disconnected")]
+    DeviceDisconnected(String),
+    #[error("Camera permission was denied by the user (backend: {backend})")]
+    PermissionDenied { backend: CaptureAPIBackend },
+    #[error("Format {requested} is not supported: {reason} (available: {available:?})")]
+    FormatNotSupported {
+        requested: FrameFormat,
+        available: Vec<FrameFormat>,
+        reason: String,
+    },
+}
+
+impl NokhwaError {
+    /// Returns `true` if this error is unrecoverable, i.e. retrying the same
+    /// operation is not expected to help and the caller should re-enumerate
+    /// devices instead.
+    #[must_use]
+    pub fn is_fatal(&self) -> bool {
+        self.kind() == NokhwaErrorKind::Fatal
+    }
+
+    /// Classifies this error as [`NokhwaErrorKind::Recoverable`] or
+    /// [`NokhwaErrorKind::Fatal`], so a capture loop can decide whether to
+    /// retry the current operation or re-enumerate devices.
+    #[must_use]
+    pub fn kind(&self) -> NokhwaErrorKind {
+        match self {
+            NokhwaError::OpenStreamError { kind, .. } | NokhwaError::ReadFrameError { kind, .. } => {
+                match kind {
+                    StreamErrorKind::DeviceNotAvailable => NokhwaErrorKind::Fatal,
+                    StreamErrorKind::BackendSpecific(_) => NokhwaErrorKind::Recoverable,
+                }
+            }
+            NokhwaError::DeviceDisconnected(_) | NokhwaError::PermissionDenied { .. } => {
+                NokhwaErrorKind::Fatal
+            }
+            NokhwaError::GetPropertyError { .. }
+            | NokhwaError::SetPropertyError { .. }
+            | NokhwaError::FormatNotSupported { .. } => NokhwaErrorKind::Recoverable,
+            _ => NokhwaErrorKind::Fatal,
+        }
+    }
+}
+
+/// A coarse classification of a [`NokhwaError`], used to decide whether the
+/// failed operation is worth retrying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NokhwaErrorKind {
+    /// The device is still usable: the same operation may succeed if retried,
+    /// or with different parameters (e.g. another format after
+    /// [`NokhwaError::FormatNotSupported`]).
+    Recoverable,
+    /// The failure will not resolve itself on its own (e.g. the device has
+    /// gone away), so the caller should re-enumerate devices instead.
+    Fatal,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stream_error_kind_follows_device_lost_flag() {
+        assert_eq!(
+            stream_error_kind(true, "ignored".to_string()),
+            StreamErrorKind::DeviceNotAvailable
+        );
+        assert_eq!(
+            stream_error_kind(false, "backend said no".to_string()),
+            StreamErrorKind::BackendSpecific("backend said no".to_string())
+        );
+    }
+
+    #[test]
+    fn device_not_available_stream_errors_are_fatal() {
+        let err = NokhwaError::ReadFrameError {
+            kind: StreamErrorKind::DeviceNotAvailable,
+            error: "gone".to_string(),
+        };
+        assert_eq!(err.kind(), NokhwaErrorKind::Fatal);
+        assert!(err.is_fatal());
+    }
+
+    #[test]
+    fn backend_specific_stream_errors_are_recoverable() {
+        let err = NokhwaError::OpenStreamError {
+            kind: StreamErrorKind::BackendSpecific("busy".to_string()),
+            error: "busy".to_string(),
+        };
+        assert_eq!(err.kind(), NokhwaErrorKind::Recoverable);
+        assert!(!err.is_fatal());
+    }
+
+    #[test]
+    fn device_disconnected_is_fatal() {
+        assert!(NokhwaError::DeviceDisconnected("cam0".to_string()).is_fatal());
+    }
+
+    #[test]
+    fn permission_denied_is_fatal() {
+        assert!(NokhwaError::PermissionDenied {
+            backend: CaptureAPIBackend::AVFoundation
+        }
+        .is_fatal());
+    }
+
+    #[test]
+    fn format_not_supported_is_recoverable() {
+        let err = NokhwaError::FormatNotSupported {
+            requested: FrameFormat::H264,
+            available: vec![FrameFormat::MJPEG],
+            reason: "no match".to_string(),
+        };
+        assert!(!err.is_fatal());
+    }
 }
 
 #[cfg(all(feature = "input-msmf", target_os = "windows"))]
@@ -87,7 +257,12 @@ impl From<BindingError> for NokhwaError {
             BindingError::DeviceOpenFailError(device, error) => {
                 NokhwaError::OpenDeviceError(device.to_string(), error)
             }
-            BindingError::ReadFrameError(error) => NokhwaError::ReadFrameError(error),
+            BindingError::ReadFrameError { error, device_lost } => {
+                NokhwaError::ReadFrameError {
+                    kind: stream_error_kind(device_lost, error.clone()),
+                    error,
+                }
+            }
             BindingError::NotImplementedError => {
                 NokhwaError::NotImplementedError("Docs-Only MediaFoundation".to_string())
             }
@@ -122,6 +297,9 @@ impl From<AVFError> for NokhwaError {
             AVFError::FailedToOpenDevice { index, why } => {
                 NokhwaError::OpenDeviceError(index.to_string(), why)
             }
+            AVFError::NotAuthorized => NokhwaError::PermissionDenied {
+                backend: CaptureAPIBackend::AVFoundation,
+            },
             AVFError::ConfigNotAccepted => NokhwaError::SetPropertyError {
                 property: "Configuration".to_string(),
                 value: "Invalid".to_string(),
@@ -130,14 +308,24 @@ impl From<AVFError> for NokhwaError {
             AVFError::General(why) => {
                 NokhwaError::GeneralError(format!("AVFoundation Error: {}", why))
             }
-            AVFError::RejectedInput => {
-                NokhwaError::OpenStreamError("AVFoundation Input Rejection".to_string())
-            }
-            AVFError::RejectedOutput => {
-                NokhwaError::OpenStreamError("AVFoundation Output Rejection".to_string())
-            }
-            AVFError::StreamOpen(why) => NokhwaError::OpenStreamError(why),
-            AVFError::ReadFrame(why) => NokhwaError::ReadFrameError(why),
+            AVFError::RejectedInput => NokhwaError::OpenStreamError {
+                kind: StreamErrorKind::BackendSpecific("AVFoundation Input Rejection".to_string()),
+                error: "AVFoundation Input Rejection".to_string(),
+            },
+            AVFError::RejectedOutput => NokhwaError::OpenStreamError {
+                kind: StreamErrorKind::BackendSpecific(
+                    "AVFoundation Output Rejection".to_string(),
+                ),
+                error: "AVFoundation Output Rejection".to_string(),
+            },
+            AVFError::StreamOpen { why, device_lost } => NokhwaError::OpenStreamError {
+                kind: stream_error_kind(device_lost, why.clone()),
+                error: why,
+            },
+            AVFError::ReadFrame { why, device_lost } => NokhwaError::ReadFrameError {
+                kind: stream_error_kind(device_lost, why.clone()),
+                error: why,
+            },
         }
     }
 }
@@ -0,0 +1,71 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::{CaptureAPIBackend, NokhwaError};
+
+/// The result of asking the OS whether this process may use the camera.
+///
+/// Mirrors `AVAuthorizationStatus` on AVFoundation; backends without a
+/// permission model never produce one of these, since
+/// [`request_camera_permission`] returns [`NokhwaError::NotImplementedError`]
+/// for them.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraAuthorization {
+    /// The user has not yet been asked.
+    NotDetermined,
+    /// The user (or a device policy) has denied access.
+    Denied,
+    /// Access is restricted by a parental control or MDM profile and cannot
+    /// be granted even by asking again.
+    Restricted,
+    /// The process may use the camera.
+    Granted,
+}
+
+/// Asks the operating system whether this process is authorized to use the
+/// camera, prompting the user if necessary.
+///
+/// On backends with no concept of camera authorization (anything other than
+/// AVFoundation today) this returns [`NokhwaError::NotImplementedError`]
+/// rather than silently claiming [`CameraAuthorization::Granted`], so callers
+/// can tell "always allowed" apart from "permission is meaningless here".
+pub async fn request_camera_permission(
+    backend: CaptureAPIBackend,
+) -> Result<CameraAuthorization, NokhwaError> {
+    match backend {
+        #[cfg(all(
+            feature = "input-avfoundation",
+            any(target_os = "macos", target_os = "ios")
+        ))]
+        CaptureAPIBackend::AVFoundation => avfoundation::request_permission().await,
+        _ => Err(NokhwaError::NotImplementedError(format!(
+            "{backend} has no camera authorization model"
+        ))),
+    }
+}
+
+#[cfg(all(
+    feature = "input-avfoundation",
+    any(target_os = "macos", target_os = "ios")
+))]
+mod avfoundation {
+    use super::CameraAuthorization;
+    use crate::NokhwaError;
+    use nokhwa_bindings_macos::{request_av_authorization, AVAuthorizationStatus};
+
+    pub(super) async fn request_permission() -> Result<CameraAuthorization, NokhwaError> {
+        let status = request_av_authorization()
+            .await
+            .map_err(NokhwaError::from)?;
+        Ok(match status {
+            AVAuthorizationStatus::NotDetermined => CameraAuthorization::NotDetermined,
+            AVAuthorizationStatus::Denied => CameraAuthorization::Denied,
+            AVAuthorizationStatus::Restricted => CameraAuthorization::Restricted,
+            AVAuthorizationStatus::Authorized => CameraAuthorization::Granted,
+        })
+    }
+}
@@ -0,0 +1,35 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{Display, Formatter};
+
+/// The pixel/stream format a frame was captured or encoded in.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameFormat {
+    MJPEG,
+    YUYV,
+    GRAY,
+    RAWRGB,
+    NV12,
+    /// H.264-encoded frames, as delivered compressed by some backends. Only
+    /// decodable when the `ffmpeg` feature is enabled.
+    H264,
+}
+
+impl Display for FrameFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            FrameFormat::MJPEG => "MJPEG",
+            FrameFormat::YUYV => "YUYV",
+            FrameFormat::GRAY => "GRAY",
+            FrameFormat::RAWRGB => "RAWRGB",
+            FrameFormat::NV12 => "NV12",
+            FrameFormat::H264 => "H264",
+        };
+        write!(f, "{name}")
+    }
+}
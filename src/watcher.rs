@@ -0,0 +1,184 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Device hot-plug notifications.
+//!
+//! Without this, a long-running application only learns a device vanished
+//! the next time it tries to read a frame and gets back a
+//! [`NokhwaError::ReadFrameError`]. [`DeviceWatcher`] instead pushes
+//! [`DeviceChangeEvent`]s as they happen, so an app can re-enumerate and
+//! reopen without polling.
+
+use crate::{CaptureAPIBackend, NokhwaError};
+use std::sync::{Arc, Mutex};
+
+/// A device add/remove notification delivered by a [`DeviceWatcher`].
+///
+/// A mid-stream disconnection is not one of these — it is delivered as
+/// `Err(`[`NokhwaError::DeviceDisconnected`]`)` instead, since it's a failure
+/// the caller needs to react to, not just an enumeration change.
+///
+/// On AVFoundation every device disconnection is delivered as
+/// `Err(`[`NokhwaError::DeviceDisconnected`]`)`, whether or not the device was
+/// streaming; [`DeviceChangeEvent::Removed`] is only produced on MediaFoundation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceChangeEvent {
+    /// A new capture device became available.
+    Added { index: String },
+    /// A previously enumerated device was removed.
+    Removed { index: String },
+}
+
+type Callback = Box<dyn FnMut(Result<DeviceChangeEvent, NokhwaError>) + Send>;
+
+/// Shared slot the backend's notification thread delivers events through.
+/// `Arc`'d so it outlives the `DeviceWatcher::new` call that registers it and
+/// can still be filled in later by [`DeviceWatcher::set_callback`].
+type SharedCallback = Arc<Mutex<Option<Callback>>>;
+
+fn dispatch(callback: &SharedCallback, event: Result<DeviceChangeEvent, NokhwaError>) {
+    let mut guard = callback.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(callback) = guard.as_mut() {
+        callback(event);
+    }
+}
+
+/// Backend-neutral handle for receiving [`DeviceChangeEvent`]s.
+///
+/// Construct one with [`DeviceWatcher::new`] and register interest with
+/// [`DeviceWatcher::set_callback`]; the closure is invoked from the backend's
+/// notification thread as [`DeviceChangeEvent`]s arrive.
+pub struct DeviceWatcher {
+    callback: SharedCallback,
+    #[cfg(all(feature = "input-msmf", target_os = "windows"))]
+    _inner: msmf::MsmfWatcher,
+    #[cfg(all(
+        feature = "input-avfoundation",
+        any(target_os = "macos", target_os = "ios")
+    ))]
+    _inner: avfoundation::AvfWatcher,
+}
+
+impl DeviceWatcher {
+    /// Starts watching for device add/remove/disconnect events on the given
+    /// backend. Returns [`NokhwaError::UnsupportedOperationError`] if the
+    /// backend has no device-notification mechanism.
+    pub fn new(backend: CaptureAPIBackend) -> Result<Self, NokhwaError> {
+        let callback: SharedCallback = Arc::new(Mutex::new(None));
+        match backend {
+            #[cfg(all(feature = "input-msmf", target_os = "windows"))]
+            CaptureAPIBackend::MediaFoundation => {
+                let inner = msmf::MsmfWatcher::register(callback.clone())?;
+                Ok(DeviceWatcher {
+                    callback,
+                    _inner: inner,
+                })
+            }
+            #[cfg(all(
+                feature = "input-avfoundation",
+                any(target_os = "macos", target_os = "ios")
+            ))]
+            CaptureAPIBackend::AVFoundation => {
+                let inner = avfoundation::AvfWatcher::register(callback.clone())?;
+                Ok(DeviceWatcher {
+                    callback,
+                    _inner: inner,
+                })
+            }
+            _ => Err(NokhwaError::UnsupportedOperationError(backend)),
+        }
+    }
+
+    /// Registers a callback invoked from the backend's notification thread
+    /// each time a [`DeviceChangeEvent`] fires, or the backend reports an
+    /// error delivering one. Replaces any previously registered callback.
+    pub fn set_callback(
+        &mut self,
+        callback: impl FnMut(Result<DeviceChangeEvent, NokhwaError>) + Send + 'static,
+    ) {
+        *self
+            .callback
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Box::new(callback));
+    }
+}
+
+#[cfg(all(feature = "input-msmf", target_os = "windows"))]
+mod msmf {
+    use super::{dispatch, DeviceChangeEvent, SharedCallback};
+    use crate::NokhwaError;
+    use nokhwa_bindings_windows::device_notification::{DeviceNotificationKind, MFDeviceNotifier};
+
+    /// Wraps an `IMFMediaSource` device-notification registration.
+    pub(super) struct MsmfWatcher {
+        _notifier: MFDeviceNotifier,
+    }
+
+    impl MsmfWatcher {
+        pub(super) fn register(callback: SharedCallback) -> Result<Self, NokhwaError> {
+            let notifier = MFDeviceNotifier::register(move |kind, index| {
+                dispatch(&callback, translate(kind, index));
+            })
+            .map_err(NokhwaError::from)?;
+            Ok(MsmfWatcher {
+                _notifier: notifier,
+            })
+        }
+    }
+
+    fn translate(
+        kind: DeviceNotificationKind,
+        index: String,
+    ) -> Result<DeviceChangeEvent, NokhwaError> {
+        match kind {
+            DeviceNotificationKind::Arrival => Ok(DeviceChangeEvent::Added { index }),
+            DeviceNotificationKind::RemovalPending | DeviceNotificationKind::RemovalComplete => {
+                Ok(DeviceChangeEvent::Removed { index })
+            }
+            DeviceNotificationKind::HardwareChanged => {
+                Err(NokhwaError::DeviceDisconnected(index))
+            }
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "input-avfoundation",
+    any(target_os = "macos", target_os = "ios")
+))]
+mod avfoundation {
+    use super::{dispatch, DeviceChangeEvent, SharedCallback};
+    use crate::NokhwaError;
+    use nokhwa_bindings_macos::notification::{AVDeviceNotification, AVDeviceNotificationObserver};
+
+    /// Wraps the `AVCaptureDeviceWasConnected`/`WasDisconnected` notification
+    /// center observers.
+    pub(super) struct AvfWatcher {
+        _observer: AVDeviceNotificationObserver,
+    }
+
+    impl AvfWatcher {
+        pub(super) fn register(callback: SharedCallback) -> Result<Self, NokhwaError> {
+            let observer = AVDeviceNotificationObserver::register(move |notification, index| {
+                dispatch(&callback, translate(notification, index));
+            })
+            .map_err(NokhwaError::from)?;
+            Ok(AvfWatcher {
+                _observer: observer,
+            })
+        }
+    }
+
+    fn translate(
+        notification: AVDeviceNotification,
+        index: String,
+    ) -> Result<DeviceChangeEvent, NokhwaError> {
+        match notification {
+            AVDeviceNotification::Connected => Ok(DeviceChangeEvent::Added { index }),
+            AVDeviceNotification::Disconnected => Err(NokhwaError::DeviceDisconnected(index)),
+        }
+    }
+}